@@ -1,11 +1,43 @@
+use bevy::asset::Asset;
+use bevy::audio::{AddAudioSource, AudioSourceBundle, Decodable, PlaybackSettings, Source};
 use bevy::prelude::*;
+use bevy::reflect::TypePath;
 use bevy::sprite::MaterialMesh2dBundle;
 use bevy::window::WindowResized;
+use bevy_inspector_egui::bevy_egui::{egui, EguiContexts, EguiPlugin};
+use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+use bevy_rapier2d::prelude::*;
 use rand::Rng;
+use rand_distr::{Distribution, Uniform};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
 
 const WINDOW_WIDTH: f32 = 800.0;
 const WINDOW_HEIGHT: f32 = 600.0;
 const PARTICLE_COUNT: usize = 100;
+const MERGE_DISTANCE: f32 = 30.0;
+const INSPIRAL_RADIUS: f32 = 80.0;
+const CHIRP_BASE_FREQUENCY: f32 = 40.0;
+const CHIRP_COALESCENCE_CONSTANT: f32 = 0.000001;
+const RINGDOWN_FREQUENCY_CONSTANT: f32 = 4000.0;
+const RINGDOWN_DURATION: f32 = 0.3;
+/// Gravitational constant used both by the particle force law and by the
+/// circular-orbit velocity computed for accretion-disk spawning.
+const GRAVITATIONAL_CONSTANT: f32 = 1.0;
+/// Coefficient in `event_horizon = (mass / 1000.0).sqrt() * coefficient`.
+const EVENT_HORIZON_COEFFICIENT: f32 = 15.0;
+const DISK_INNER_RADIUS: f32 = 40.0;
+const DISK_OUTER_RADIUS: f32 = 200.0;
+/// Opening-angle threshold for the Barnes-Hut approximation: a node is
+/// treated as a single mass once its width/distance ratio drops below this.
+const BARNES_HUT_THETA: f32 = 0.5;
+/// Below this region diagonal length, stop subdividing the quadtree even if
+/// it still holds more than one body (guards against near-coincident bodies).
+const QUADTREE_MIN_SIZE: f32 = 1.0;
+const SCENARIO_DIR: &str = "scenarios";
+const SAVED_SCENARIO_PATH: &str = "scenarios/saved.json";
 
 #[derive(Component)]
 struct Particle {
@@ -19,20 +51,258 @@ struct BlackHole {
     event_horizon: f32,
 }
 
-#[derive(Resource)]
+#[derive(Clone, Copy, PartialEq, Debug, Default, Reflect, Serialize, Deserialize)]
+enum SpawnMode {
+    /// Particles scattered uniformly across the window with small random velocities.
+    #[default]
+    Scatter,
+    /// Particles seeded into a Keplerian ring around a black hole.
+    AccretionDisk,
+}
+
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
 struct SimulationState {
     paused: bool,
     selected_black_hole: usize,
     particle_size: f32,
     time_scale: f32,
+    spawn_mode: SpawnMode,
+    disk_inner_radius: f32,
+    disk_outer_radius: f32,
+    mutual_gravity_enabled: bool,
+    theta: f32,
+    physics_backed: bool,
+}
+
+impl Default for SimulationState {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            selected_black_hole: 0,
+            particle_size: 1.0,
+            time_scale: 1.0,
+            spawn_mode: SpawnMode::Scatter,
+            disk_inner_radius: DISK_INNER_RADIUS,
+            disk_outer_radius: DISK_OUTER_RADIUS,
+            mutual_gravity_enabled: false,
+            theta: BARNES_HUT_THETA,
+            physics_backed: false,
+        }
+    }
+}
+
+/// Tunables that used to be hardcoded magic numbers, now editable at runtime
+/// from the inspector panel instead of requiring a recompile.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+struct SimConstants {
+    gravitational_constant: f32,
+    merge_distance: f32,
+    event_horizon_coefficient: f32,
+    inspiral_radius: f32,
+    particle_count_target: usize,
+}
+
+impl Default for SimConstants {
+    fn default() -> Self {
+        Self {
+            gravitational_constant: GRAVITATIONAL_CONSTANT,
+            merge_distance: MERGE_DISTANCE,
+            event_horizon_coefficient: EVENT_HORIZON_COEFFICIENT,
+            inspiral_radius: INSPIRAL_RADIUS,
+            particle_count_target: PARTICLE_COUNT,
+        }
+    }
+}
+
+/// A single `BlackHole` as captured into a `Scenario` file: everything
+/// needed to respawn it with the right size and position.
+#[derive(Serialize, Deserialize)]
+struct ScenarioBlackHole {
+    mass: f32,
+    position: Vec2,
+}
+
+/// A persistable snapshot of the simulation: black-hole layout plus the
+/// tunable parts of `SimulationState`. Particle layout is not captured;
+/// loading a scenario reseeds particles the same way `setup` does. New
+/// fields default to `setup`'s own starting values, so scenario files saved
+/// before they existed still load.
+#[derive(Serialize, Deserialize)]
+struct Scenario {
+    black_holes: Vec<ScenarioBlackHole>,
+    time_scale: f32,
+    particle_size: f32,
+    #[serde(default)]
+    spawn_mode: SpawnMode,
+    #[serde(default)]
+    mutual_gravity_enabled: bool,
+    #[serde(default = "default_theta")]
+    theta: f32,
+    #[serde(default)]
+    physics_backed: bool,
+    #[serde(default = "default_disk_inner_radius")]
+    disk_inner_radius: f32,
+    #[serde(default = "default_disk_outer_radius")]
+    disk_outer_radius: f32,
+}
+
+fn default_theta() -> f32 {
+    BARNES_HUT_THETA
+}
+
+fn default_disk_inner_radius() -> f32 {
+    DISK_INNER_RADIUS
+}
+
+fn default_disk_outer_radius() -> f32 {
+    DISK_OUTER_RADIUS
 }
 
 #[derive(Component)]
 struct GravitationalWave {
     lifetime: Timer,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ChirpPhase {
+    Inspiral,
+    Ringdown,
+}
+
+/// Tracks a single inspiraling black-hole pair and drives the sonification
+/// oscillator from the leading-order chirp law until merger, then a damped
+/// ringdown.
+#[derive(Component)]
+struct Chirp {
+    black_hole_a: Entity,
+    black_hole_b: Entity,
+    f0: f32,
+    tau: f32,
+    elapsed: f32,
+    phase: ChirpPhase,
+    ringdown_frequency: f32,
+    ringdown_timer: Timer,
     intensity: f32,
 }
 
+/// The audible output of the chirp sonification, updated every frame.
+/// Mirrors `ChirpAudioState` at frame cadence for introspection; the actual
+/// sound comes from `ChirpAudioState`, read every sample by `ChirpWaveDecoder`
+/// on the audio thread.
+#[derive(Resource, Default)]
+struct ChirpOscillator {
+    frequency: f32,
+    amplitude: f32,
+    phase: f32,
+}
+
+/// Lock-free bridge between `update_chirps` (ECS, frame cadence) and
+/// `ChirpWaveDecoder` (audio thread, sample cadence): the oscillator's
+/// instantaneous frequency and amplitude, shared via atomics so neither side
+/// blocks the other.
+#[derive(Resource, Clone)]
+struct ChirpAudioState {
+    frequency_bits: Arc<AtomicU32>,
+    amplitude_bits: Arc<AtomicU32>,
+}
+
+impl ChirpAudioState {
+    fn new() -> Self {
+        Self {
+            frequency_bits: Arc::new(AtomicU32::new(0f32.to_bits())),
+            amplitude_bits: Arc::new(AtomicU32::new(0f32.to_bits())),
+        }
+    }
+
+    fn set(&self, frequency: f32, amplitude: f32) {
+        self.frequency_bits.store(frequency.to_bits(), Ordering::Relaxed);
+        self.amplitude_bits.store(amplitude.to_bits(), Ordering::Relaxed);
+    }
+
+    fn frequency(&self) -> f32 {
+        f32::from_bits(self.frequency_bits.load(Ordering::Relaxed))
+    }
+
+    fn amplitude(&self) -> f32 {
+        f32::from_bits(self.amplitude_bits.load(Ordering::Relaxed))
+    }
+}
+
+/// Custom audio asset that makes the chirp sonification actually audible:
+/// its decoder synthesizes a sine wave in real time from `ChirpAudioState`
+/// instead of playing back pre-baked samples.
+#[derive(Asset, TypePath)]
+struct ChirpWave {
+    state: ChirpAudioState,
+}
+
+impl Decodable for ChirpWave {
+    type DecoderItem = f32;
+    type Decoder = ChirpWaveDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        ChirpWaveDecoder {
+            state: self.state.clone(),
+            sample_rate: 44_100,
+            phase: 0.0,
+        }
+    }
+}
+
+/// Streams an endless sine wave whose frequency and amplitude track
+/// `ChirpAudioState` sample-by-sample, so pitch/loudness changes made by
+/// `update_chirps` are heard continuously rather than as discrete clips.
+struct ChirpWaveDecoder {
+    state: ChirpAudioState,
+    sample_rate: u32,
+    phase: f32,
+}
+
+impl Iterator for ChirpWaveDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let frequency = self.state.frequency();
+        let amplitude = self.state.amplitude().clamp(0.0, 1.0);
+        self.phase = (self.phase + frequency / self.sample_rate as f32).fract();
+        Some((self.phase * std::f32::consts::TAU).sin() * amplitude)
+    }
+}
+
+impl Source for ChirpWaveDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+/// Registers `ChirpWave` and spawns the looping procedural audio source that
+/// `update_chirps` drives via `ChirpAudioState`.
+fn spawn_chirp_audio(
+    mut commands: Commands,
+    mut chirp_waves: ResMut<Assets<ChirpWave>>,
+    chirp_audio_state: Res<ChirpAudioState>,
+) {
+    let handle = chirp_waves.add(ChirpWave { state: chirp_audio_state.clone() });
+    commands.spawn(AudioSourceBundle {
+        source: handle,
+        settings: PlaybackSettings::LOOP,
+    });
+}
+
 fn spawn_gravitational_wave(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
@@ -49,7 +319,6 @@ fn spawn_gravitational_wave(
         },
         GravitationalWave {
             lifetime: Timer::from_seconds(2.0, TimerMode::Once),
-            intensity,
         },
     ));
 }
@@ -92,26 +361,40 @@ fn main() {
             }),
             ..default()
         }))
-        .insert_resource(SimulationState {
-            paused: false,
-            selected_black_hole: 0,
-            particle_size: 1.0,
-            time_scale: 1.0,
-        })
-        .add_systems(Startup, setup)
+        .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0))
+        .add_plugins(EguiPlugin)
+        .add_plugins(ResourceInspectorPlugin::<SimulationState>::default())
+        .add_plugins(ResourceInspectorPlugin::<SimConstants>::default())
+        .register_type::<SpawnMode>()
+        .register_type::<SimulationState>()
+        .register_type::<SimConstants>()
+        .add_audio_source::<ChirpWave>()
+        .init_resource::<SimConstants>()
+        .init_resource::<SimulationState>()
+        .insert_resource(ChirpOscillator::default())
+        .insert_resource(ChirpAudioState::new())
+        .add_systems(Startup, (setup, spawn_chirp_audio))
         .add_systems(
             Update,
             (
                 update_particles,
                 update_black_holes,
                 handle_input,
-                update_ui,
+                draw_inspector_panel,
                 handle_window_resize,
                 update_gravitational_waves,
                 merge_black_holes,
                 update_particle_color,
+                start_chirps,
+                update_chirps,
+                save_scenario,
+                load_scenario,
+                sync_physics_bodies,
+                apply_physics_gravity,
+                sync_physics_velocity,
             ),
         )
+        .add_systems(PostUpdate, handle_physics_collisions)
         .run();
 }
 
@@ -120,6 +403,8 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     windows: Query<&Window>,
+    simulation_state: Res<SimulationState>,
+    sim_constants: Res<SimConstants>,
 ) {
     let window = windows.single();
     let width = window.width();
@@ -128,9 +413,62 @@ fn setup(
     commands.spawn(Camera2dBundle::default());
 
     let mut rng = rand::thread_rng();
+    let black_hole_position = Vec3::new(width / 2.0, height / 2.0, 0.0);
+    let black_hole_mass: f32 = 1000.0;
+    let black_hole_event_horizon =
+        (black_hole_mass / 1000.0).sqrt() * sim_constants.event_horizon_coefficient;
 
-    // Spawn particles
-    for _ in 0..PARTICLE_COUNT {
+    match simulation_state.spawn_mode {
+        SpawnMode::Scatter => spawn_scatter_particles(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut rng,
+            width,
+            height,
+            sim_constants.particle_count_target,
+        ),
+        SpawnMode::AccretionDisk => spawn_disk_particles(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut rng,
+            black_hole_position,
+            black_hole_mass,
+            simulation_state.disk_inner_radius,
+            simulation_state.disk_outer_radius,
+            sim_constants.particle_count_target,
+            sim_constants.gravitational_constant,
+        ),
+    }
+
+    // Spawn initial black hole
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes.add(Circle::new(black_hole_event_horizon)).into(),
+            material: materials.add(ColorMaterial::from(Color::BLACK)),
+            transform: Transform::from_translation(black_hole_position),
+            ..default()
+        },
+        BlackHole {
+            mass: black_hole_mass,
+            event_horizon: black_hole_event_horizon,
+        },
+    ));
+}
+
+/// Scatters `count` particles uniformly across the window with small random
+/// velocities (the original free-fall behavior).
+fn spawn_scatter_particles(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    rng: &mut impl Rng,
+    width: f32,
+    height: f32,
+    count: usize,
+) {
+    for _ in 0..count {
         let position = Vec2::new(
             rng.gen_range(0.0..width),
             height - rng.gen_range(0.0..height), // Invert Y
@@ -153,20 +491,57 @@ fn setup(
             Particle { velocity, mass },
         ));
     }
+}
 
-    // Spawn initial black hole
-    commands.spawn((
-        MaterialMesh2dBundle {
-            mesh: meshes.add(Circle::new(15.0)).into(),
-            material: materials.add(ColorMaterial::from(Color::BLACK)),
-            transform: Transform::from_translation(Vec3::new(width / 2.0, height / 2.0, 0.0)),
-            ..default()
-        },
-        BlackHole {
-            mass: 1000.0,
-            event_horizon: 15.0,
-        },
-    ));
+/// Seeds `count` particles into a Keplerian accretion disk around `center`:
+/// angle uniform over `[0, TAU)`, radius uniform over `[inner, outer)`, and a
+/// circular-orbit tangential velocity `v = sqrt(G*M / r)` so particles stay
+/// in a stable ring instead of spiraling straight in.
+#[allow(clippy::too_many_arguments)]
+fn spawn_disk_particles(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    rng: &mut impl Rng,
+    center: Vec3,
+    center_mass: f32,
+    inner_radius: f32,
+    outer_radius: f32,
+    count: usize,
+    gravitational_constant: f32,
+) {
+    let angle_distribution = Uniform::new(0.0, std::f32::consts::TAU);
+    let radius_distribution = Uniform::new(inner_radius, outer_radius);
+
+    for _ in 0..count {
+        let angle = angle_distribution.sample(rng);
+        let radius = radius_distribution.sample(rng);
+        let offset = Vec2::new(angle.cos(), angle.sin()) * radius;
+        let position = center.truncate() + offset;
+
+        // Tangential direction perpendicular to the radius vector, scaled to
+        // the circular-orbit speed for this radius.
+        let tangent = Vec2::new(-angle.sin(), angle.cos());
+        let orbital_speed = (gravitational_constant * center_mass / radius).sqrt();
+        let velocity = tangent * orbital_speed;
+
+        let color = Color::srgb(
+            rng.gen_range(0.5..1.0),
+            rng.gen_range(0.5..1.0),
+            rng.gen_range(0.5..1.0),
+        );
+        let mass = rng.gen_range(0.1..1.0);
+
+        commands.spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(Circle::new(mass)).into(),
+                material: materials.add(ColorMaterial::from(color)),
+                transform: Transform::from_translation(position.extend(0.0)),
+                ..default()
+            },
+            Particle { velocity, mass },
+        ));
+    }
 }
 
 fn handle_window_resize(
@@ -201,19 +576,168 @@ fn update_particle_color(
     }
 }
 
+/// A point mass fed into the Barnes-Hut quadtree: either a particle or a
+/// black hole, tagged with its entity so a body never attracts itself.
+#[derive(Clone, Copy)]
+struct Body {
+    entity: Entity,
+    position: Vec2,
+    mass: f32,
+}
+
+/// A node of the Barnes-Hut quadtree covering `[min, max]`. Leaves hold a
+/// single `Body`; internal nodes store the aggregate mass and center-of-mass
+/// of everything beneath them so distant clusters can be treated as one.
+struct QuadTreeNode {
+    min: Vec2,
+    max: Vec2,
+    mass: f32,
+    center_of_mass: Vec2,
+    body: Option<Body>,
+    children: Option<Box<[QuadTreeNode; 4]>>,
+}
+
+/// Inverse-square force from a source at `direction` (source position minus
+/// query position) with the given `mass_product = mass_a * mass_b`. Guards
+/// the case where `direction` is the exact zero vector (source and query
+/// coincide) before normalizing it, since `Vec2::ZERO.normalize()` is NaN
+/// regardless of how the separately-clamped distance scalar is floored.
+fn gravitational_force(direction: Vec2, mass_product: f32, g: f32) -> Vec2 {
+    let raw_distance = direction.length();
+    if raw_distance <= f32::EPSILON {
+        return Vec2::ZERO;
+    }
+    let distance = raw_distance.max(1.0);
+    let magnitude = g * mass_product / (distance * distance);
+    direction.normalize() * magnitude
+}
+
+impl QuadTreeNode {
+    fn build(bodies: &[Body], min: Vec2, max: Vec2) -> Self {
+        if bodies.is_empty() {
+            return QuadTreeNode {
+                min,
+                max,
+                mass: 0.0,
+                center_of_mass: (min + max) / 2.0,
+                body: None,
+                children: None,
+            };
+        }
+
+        if bodies.len() == 1 || (max - min).length() < QUADTREE_MIN_SIZE {
+            let mass: f32 = bodies.iter().map(|b| b.mass).sum();
+            let center_of_mass =
+                bodies.iter().map(|b| b.position * b.mass).sum::<Vec2>() / mass;
+            return QuadTreeNode {
+                min,
+                max,
+                mass,
+                center_of_mass,
+                body: if bodies.len() == 1 { Some(bodies[0]) } else { None },
+                children: None,
+            };
+        }
+
+        let center = (min + max) / 2.0;
+        let mut quadrants: [Vec<Body>; 4] = Default::default();
+        for &body in bodies {
+            quadrants[quadrant_index(body.position, center)].push(body);
+        }
+
+        let bounds = quadrant_bounds(min, max, center);
+        let children: [QuadTreeNode; 4] =
+            std::array::from_fn(|i| QuadTreeNode::build(&quadrants[i], bounds[i].0, bounds[i].1));
+
+        let mass: f32 = children.iter().map(|c| c.mass).sum();
+        let center_of_mass = if mass > 0.0 {
+            children.iter().map(|c| c.center_of_mass * c.mass).sum::<Vec2>() / mass
+        } else {
+            center
+        };
+
+        QuadTreeNode {
+            min,
+            max,
+            mass,
+            center_of_mass,
+            body: None,
+            children: Some(Box::new(children)),
+        }
+    }
+
+    /// Gravitational force (repo convention: not divided by the querying
+    /// body's mass, matching the existing direct black-hole force law) that
+    /// this node's subtree exerts on `entity` sitting at `position`.
+    fn force_on(&self, entity: Entity, position: Vec2, query_mass: f32, theta: f32, g: f32) -> Vec2 {
+        if self.mass <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        if let Some(body) = self.body {
+            if body.entity == entity {
+                return Vec2::ZERO;
+            }
+            let direction = body.position - position;
+            return gravitational_force(direction, body.mass * query_mass, g);
+        }
+
+        let direction = self.center_of_mass - position;
+        let distance = direction.length().max(1.0);
+        let size = self.max.x - self.min.x;
+
+        if size / distance < theta {
+            gravitational_force(direction, self.mass * query_mass, g)
+        } else if let Some(children) = &self.children {
+            children
+                .iter()
+                .map(|child| child.force_on(entity, position, query_mass, theta, g))
+                .sum()
+        } else {
+            Vec2::ZERO
+        }
+    }
+}
+
+fn quadrant_index(position: Vec2, center: Vec2) -> usize {
+    match (position.x < center.x, position.y < center.y) {
+        (true, true) => 0,
+        (false, true) => 1,
+        (true, false) => 2,
+        (false, false) => 3,
+    }
+}
+
+fn quadrant_bounds(min: Vec2, max: Vec2, center: Vec2) -> [(Vec2, Vec2); 4] {
+    [
+        (min, center),
+        (Vec2::new(center.x, min.y), Vec2::new(max.x, center.y)),
+        (Vec2::new(min.x, center.y), Vec2::new(center.x, max.y)),
+        (center, max),
+    ]
+}
+
+#[allow(clippy::type_complexity)]
 fn update_particles(
     mut param_set: ParamSet<(
-        Query<(&mut Transform, &mut Particle)>,
-        Query<(&Transform, &BlackHole)>,
+        Query<(Entity, &mut Transform, &mut Particle)>,
+        Query<(Entity, &Transform, &BlackHole)>,
     )>,
     time: Res<Time>,
     simulation_state: Res<SimulationState>,
+    sim_constants: Res<SimConstants>,
     windows: Query<&Window>,
 ) {
     if simulation_state.paused {
         return;
     }
 
+    // Rapier owns integration, capture and merging in physics-backed mode;
+    // see `apply_physics_gravity` and `handle_physics_collisions`.
+    if simulation_state.physics_backed {
+        return;
+    }
+
     let window = windows.single();
     let width = window.width();
     let height = window.height();
@@ -221,11 +745,12 @@ fn update_particles(
     // Apply time scale to delta time
     let scaled_delta_time = time.delta_seconds() * simulation_state.time_scale;
 
-    let black_holes: Vec<(Vec3, f32, f32)> = param_set
+    let black_holes: Vec<(Entity, Vec3, f32, f32)> = param_set
         .p1()
         .iter()
-        .map(|(transform, black_hole)| {
+        .map(|(entity, transform, black_hole)| {
             (
+                entity,
                 transform.translation,
                 black_hole.mass,
                 black_hole.event_horizon,
@@ -233,8 +758,32 @@ fn update_particles(
         })
         .collect();
 
-    for (mut transform, mut particle) in param_set.p0().iter_mut() {
-        for &(black_hole_position, black_hole_mass, event_horizon) in &black_holes {
+    let quadtree = if simulation_state.mutual_gravity_enabled {
+        let mut bodies: Vec<Body> = param_set
+            .p0()
+            .iter()
+            .map(|(entity, transform, particle)| Body {
+                entity,
+                position: transform.translation.truncate(),
+                mass: particle.mass,
+            })
+            .collect();
+        bodies.extend(black_holes.iter().map(|&(entity, position, mass, _)| Body {
+            entity,
+            position: position.truncate(),
+            mass,
+        }));
+        Some(QuadTreeNode::build(
+            &bodies,
+            Vec2::ZERO,
+            Vec2::new(width, height),
+        ))
+    } else {
+        None
+    };
+
+    for (entity, mut transform, mut particle) in param_set.p0().iter_mut() {
+        for &(_, black_hole_position, black_hole_mass, event_horizon) in &black_holes {
             let direction = black_hole_position - transform.translation;
             let distance = direction.length();
 
@@ -249,13 +798,25 @@ fn update_particles(
                     rand::random::<f32>() * 2.0 - 1.0,
                     rand::random::<f32>() * 2.0 - 1.0,
                 );
-            } else {
-                let force = (black_hole_mass * particle.mass) / (distance * distance);
+            } else if !simulation_state.mutual_gravity_enabled {
+                let force = sim_constants.gravitational_constant * (black_hole_mass * particle.mass)
+                    / (distance * distance);
                 // Use scaled_delta_time here
                 particle.velocity += direction.normalize().truncate() * force * scaled_delta_time;
             }
         }
 
+        if let Some(tree) = &quadtree {
+            let force = tree.force_on(
+                entity,
+                transform.translation.truncate(),
+                particle.mass,
+                simulation_state.theta,
+                sim_constants.gravitational_constant,
+            );
+            particle.velocity += force * scaled_delta_time;
+        }
+
         // Use scaled_delta_time here as well
         transform.translation += particle.velocity.extend(0.0) * scaled_delta_time;
 
@@ -270,7 +831,15 @@ fn merge_black_holes(
     black_holes: Query<(Entity, &mut Transform, &mut BlackHole)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    mut chirps: Query<&mut Chirp>,
+    simulation_state: Res<SimulationState>,
+    sim_constants: Res<SimConstants>,
 ) {
+    // Physics-backed mode merges on contact via `handle_physics_collisions`.
+    if simulation_state.physics_backed {
+        return;
+    }
+
     let mut to_merge = Vec::new();
     let black_hole_data: Vec<(Entity, Vec3, f32)> = black_holes
         .iter()
@@ -283,8 +852,7 @@ fn merge_black_holes(
             let (entity2, pos2, mass2) = black_hole_data[j];
             let distance = pos1.distance(pos2);
 
-            if distance < 30.0 {
-                // Adjust this threshold as needed
+            if distance < sim_constants.merge_distance {
                 to_merge.push((entity1, entity2, (pos1 + pos2) / 2.0, mass1 + mass2));
             }
         }
@@ -294,21 +862,27 @@ fn merge_black_holes(
         commands.entity(entity1).despawn();
         commands.entity(entity2).despawn();
 
-        let new_event_horizon = (new_mass / 1000.0).sqrt() * 15.0;
-        let new_size = new_event_horizon * 2.0;
+        // Hand the matching inspiral chirp off to its ringdown phase so the
+        // merger audio stays coupled to the gravitational wave it spawns.
+        for mut chirp in chirps.iter_mut() {
+            let tracks_this_pair = (chirp.black_hole_a == entity1 && chirp.black_hole_b == entity2)
+                || (chirp.black_hole_a == entity2 && chirp.black_hole_b == entity1);
+            if tracks_this_pair {
+                chirp.phase = ChirpPhase::Ringdown;
+                chirp.ringdown_frequency = RINGDOWN_FREQUENCY_CONSTANT / new_mass;
+                chirp.ringdown_timer = Timer::from_seconds(RINGDOWN_DURATION, TimerMode::Once);
+                chirp.intensity = new_mass / 1000.0;
+            }
+        }
 
-        commands.spawn((
-            MaterialMesh2dBundle {
-                mesh: meshes.add(Circle::new(new_size / 2.0)).into(),
-                material: materials.add(ColorMaterial::from(Color::BLACK)),
-                transform: Transform::from_translation(new_pos).with_scale(Vec3::splat(new_size)),
-                ..default()
-            },
-            BlackHole {
-                mass: new_mass,
-                event_horizon: new_event_horizon,
-            },
-        ));
+        spawn_black_hole(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            new_pos,
+            new_mass,
+            sim_constants.event_horizon_coefficient,
+        );
 
         spawn_gravitational_wave(
             &mut commands,
@@ -320,12 +894,356 @@ fn merge_black_holes(
     }
 }
 
+/// Spawns a `BlackHole` of the given `mass` at `position`, deriving its
+/// event horizon and visual size the same way `merge_black_holes` always has.
+fn spawn_black_hole(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    position: Vec3,
+    mass: f32,
+    event_horizon_coefficient: f32,
+) -> Entity {
+    let event_horizon = (mass / 1000.0).sqrt() * event_horizon_coefficient;
+    let size = event_horizon * 2.0;
+
+    commands
+        .spawn((
+            MaterialMesh2dBundle {
+                mesh: meshes.add(Circle::new(size / 2.0)).into(),
+                material: materials.add(ColorMaterial::from(Color::BLACK)),
+                transform: Transform::from_translation(position).with_scale(Vec3::splat(size)),
+                ..default()
+            },
+            BlackHole { mass, event_horizon },
+        ))
+        .id()
+}
+
+/// Attaches (or, if physics mode was just turned off, removes) the Rapier
+/// components that let particles and black holes be driven by the physics
+/// engine instead of by `update_particles`'s hand-rolled integration.
+///
+/// Rapier scales a `Collider` by the entity's global `Transform.scale`, but
+/// `spawn_black_hole`/`update_black_holes` bake the visual size into that
+/// same scale (the original `setup()` black hole is the one exception, left
+/// at scale 1). The collider radius is divided by the current scale so
+/// every black hole's hitbox matches its real event horizon regardless of
+/// which convention spawned it.
+fn sync_physics_bodies(
+    mut commands: Commands,
+    simulation_state: Res<SimulationState>,
+    unfitted_particles: Query<(Entity, &Particle), Without<RigidBody>>,
+    unfitted_black_holes: Query<(Entity, &Transform, &BlackHole), Without<RigidBody>>,
+    fitted_particles: Query<Entity, (With<Particle>, With<RigidBody>)>,
+    fitted_black_holes: Query<Entity, (With<BlackHole>, With<RigidBody>)>,
+) {
+    if simulation_state.physics_backed {
+        for (entity, particle) in &unfitted_particles {
+            commands.entity(entity).insert((
+                RigidBody::Dynamic,
+                Collider::ball(particle.mass.max(0.5)),
+                Velocity::linear(particle.velocity),
+                ExternalForce::default(),
+                Damping::default(),
+                ActiveEvents::COLLISION_EVENTS,
+            ));
+        }
+        for (entity, transform, black_hole) in &unfitted_black_holes {
+            let collider_radius = black_hole.event_horizon / transform.scale.x.max(f32::EPSILON);
+            commands.entity(entity).insert((
+                RigidBody::Fixed,
+                Collider::ball(collider_radius),
+                Sensor,
+                ActiveEvents::COLLISION_EVENTS,
+                // Fixed-fixed pairs are ignored by default; enable them so two
+                // black holes report their contact and can fuse.
+                ActiveCollisionTypes::default() | ActiveCollisionTypes::FIXED_FIXED,
+            ));
+        }
+    } else {
+        for entity in &fitted_particles {
+            commands
+                .entity(entity)
+                .remove::<(RigidBody, Collider, Velocity, ExternalForce, Damping, ActiveEvents)>();
+        }
+        for entity in &fitted_black_holes {
+            commands
+                .entity(entity)
+                .remove::<(RigidBody, Collider, Sensor, ActiveEvents, ActiveCollisionTypes)>();
+        }
+    }
+}
+
+/// Applies black-hole gravity to physics-backed particles as an external
+/// force each step, rather than mutating `Particle::velocity` directly.
+fn apply_physics_gravity(
+    mut particles: Query<(&Transform, &Particle, &mut ExternalForce), With<RigidBody>>,
+    black_holes: Query<(&Transform, &BlackHole)>,
+    simulation_state: Res<SimulationState>,
+    sim_constants: Res<SimConstants>,
+) {
+    if !simulation_state.physics_backed || simulation_state.paused {
+        return;
+    }
+
+    let black_hole_data: Vec<(Vec3, f32)> = black_holes
+        .iter()
+        .map(|(transform, black_hole)| (transform.translation, black_hole.mass))
+        .collect();
+
+    for (transform, particle, mut external_force) in &mut particles {
+        let mut force = Vec2::ZERO;
+        for &(position, mass) in &black_hole_data {
+            let direction = position - transform.translation;
+            let distance = direction.length().max(1.0);
+            force += direction.normalize().truncate() * sim_constants.gravitational_constant
+                * mass
+                * particle.mass
+                / (distance * distance);
+        }
+        external_force.force = force;
+    }
+}
+
+/// Mirrors Rapier's `Velocity` into `Particle::velocity` so systems like
+/// `update_particle_color` keep working unchanged in physics-backed mode.
+fn sync_physics_velocity(mut particles: Query<(&Velocity, &mut Particle)>) {
+    for (velocity, mut particle) in &mut particles {
+        particle.velocity = velocity.linvel;
+    }
+}
+
+/// Reacts to Rapier `CollisionEvent`s in physics-backed mode: two
+/// overlapping black holes fuse exactly like `merge_black_holes`, and a
+/// particle touching a black hole's event-horizon sensor is captured.
+#[allow(clippy::too_many_arguments)]
+fn handle_physics_collisions(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    black_holes: Query<(&Transform, &BlackHole)>,
+    particles: Query<Entity, With<Particle>>,
+    mut chirps: Query<&mut Chirp>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    simulation_state: Res<SimulationState>,
+    sim_constants: Res<SimConstants>,
+    windows: Query<&Window>,
+) {
+    if !simulation_state.physics_backed {
+        return;
+    }
+
+    let window = windows.single();
+
+    for event in collision_events.read() {
+        let CollisionEvent::Started(entity_a, entity_b, _) = event else {
+            continue;
+        };
+
+        match (black_holes.get(*entity_a), black_holes.get(*entity_b)) {
+            (Ok((transform_a, black_hole_a)), Ok((transform_b, black_hole_b))) => {
+                let new_pos = (transform_a.translation + transform_b.translation) / 2.0;
+                let new_mass = black_hole_a.mass + black_hole_b.mass;
+
+                commands.entity(*entity_a).despawn();
+                commands.entity(*entity_b).despawn();
+
+                for mut chirp in chirps.iter_mut() {
+                    let tracks_this_pair = (chirp.black_hole_a == *entity_a
+                        && chirp.black_hole_b == *entity_b)
+                        || (chirp.black_hole_a == *entity_b && chirp.black_hole_b == *entity_a);
+                    if tracks_this_pair {
+                        chirp.phase = ChirpPhase::Ringdown;
+                        chirp.ringdown_frequency = RINGDOWN_FREQUENCY_CONSTANT / new_mass;
+                        chirp.ringdown_timer = Timer::from_seconds(RINGDOWN_DURATION, TimerMode::Once);
+                        chirp.intensity = new_mass / 1000.0;
+                    }
+                }
+
+                spawn_black_hole(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    new_pos,
+                    new_mass,
+                    sim_constants.event_horizon_coefficient,
+                );
+                spawn_gravitational_wave(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    new_pos,
+                    new_mass / 1000.0,
+                );
+            }
+            (Ok(_), Err(_)) if particles.get(*entity_b).is_ok() => {
+                commands.entity(*entity_b).despawn();
+                let mut rng = rand::thread_rng();
+                spawn_scatter_particles(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    &mut rng,
+                    window.width(),
+                    window.height(),
+                    1,
+                );
+            }
+            (Err(_), Ok(_)) if particles.get(*entity_a).is_ok() => {
+                commands.entity(*entity_a).despawn();
+                let mut rng = rand::thread_rng();
+                spawn_scatter_particles(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    &mut rng,
+                    window.width(),
+                    window.height(),
+                    1,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Leading-order time-to-coalescence estimate for this sim's mass scale:
+/// `tau` grows with `distance^4` and shrinks with `total_mass^3`, floored so
+/// a pair already near the merge distance still gets a short audible sweep
+/// instead of a zero/negative duration.
+fn chirp_tau(distance: f32, total_mass: f32) -> f32 {
+    (distance.powi(4) / (CHIRP_COALESCENCE_CONSTANT * total_mass.powi(3))).max(0.1)
+}
+
+/// Leading-order inspiral frequency sweep `f(t) = f0 * (1 - t/tau)^(-3/8)`,
+/// diverging as `t_over_tau` approaches 1 (coalescence).
+fn chirp_frequency(f0: f32, t_over_tau: f32) -> f32 {
+    f0 * (1.0 - t_over_tau).powf(-3.0 / 8.0)
+}
+
+/// Watches for black-hole pairs that have entered the inspiral radius (but
+/// haven't merged yet) and begins tracking them with a `Chirp`.
+fn start_chirps(
+    mut commands: Commands,
+    black_holes: Query<(Entity, &Transform, &BlackHole)>,
+    existing_chirps: Query<&Chirp>,
+    sim_constants: Res<SimConstants>,
+) {
+    let black_hole_data: Vec<(Entity, Vec3, f32)> = black_holes
+        .iter()
+        .map(|(entity, transform, black_hole)| (entity, transform.translation, black_hole.mass))
+        .collect();
+
+    for i in 0..black_hole_data.len() {
+        for j in i + 1..black_hole_data.len() {
+            let (entity1, pos1, mass1) = black_hole_data[i];
+            let (entity2, pos2, mass2) = black_hole_data[j];
+            let distance = pos1.distance(pos2);
+
+            if distance < sim_constants.merge_distance || distance >= sim_constants.inspiral_radius {
+                continue;
+            }
+
+            let already_tracked = existing_chirps.iter().any(|chirp| {
+                (chirp.black_hole_a == entity1 && chirp.black_hole_b == entity2)
+                    || (chirp.black_hole_a == entity2 && chirp.black_hole_b == entity1)
+            });
+            if already_tracked {
+                continue;
+            }
+
+            let total_mass = mass1 + mass2;
+            let tau = chirp_tau(distance, total_mass);
+
+            commands.spawn(Chirp {
+                black_hole_a: entity1,
+                black_hole_b: entity2,
+                f0: CHIRP_BASE_FREQUENCY,
+                tau,
+                elapsed: 0.0,
+                phase: ChirpPhase::Inspiral,
+                ringdown_frequency: 0.0,
+                ringdown_timer: Timer::from_seconds(RINGDOWN_DURATION, TimerMode::Once),
+                intensity: total_mass / 1000.0,
+            });
+        }
+    }
+}
+
+/// Advances each tracked `Chirp` through its inspiral sweep or ringdown decay
+/// and mixes the loudest one into the `ChirpOscillator` resource each frame.
+fn update_chirps(
+    mut commands: Commands,
+    mut chirp_query: Query<(Entity, &mut Chirp)>,
+    black_holes: Query<&Transform, With<BlackHole>>,
+    time: Res<Time>,
+    simulation_state: Res<SimulationState>,
+    mut oscillator: ResMut<ChirpOscillator>,
+    chirp_audio_state: Res<ChirpAudioState>,
+) {
+    let scaled_delta = time.delta_seconds() * simulation_state.time_scale;
+    let mut loudest: Option<(f32, f32)> = None;
+
+    for (entity, mut chirp) in chirp_query.iter_mut() {
+        let sample = match chirp.phase {
+            ChirpPhase::Inspiral => {
+                if black_holes.get(chirp.black_hole_a).is_err()
+                    || black_holes.get(chirp.black_hole_b).is_err()
+                {
+                    // A black hole vanished (merged elsewhere, deleted by the
+                    // player) without ever handing this chirp to ringdown.
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+
+                chirp.elapsed += scaled_delta;
+                let t_over_tau = (chirp.elapsed / chirp.tau).min(0.999);
+                let frequency = chirp_frequency(chirp.f0, t_over_tau);
+                let amplitude = t_over_tau * chirp.intensity;
+                Some((frequency, amplitude))
+            }
+            ChirpPhase::Ringdown => {
+                chirp.ringdown_timer.tick(time.delta());
+                if chirp.ringdown_timer.finished() {
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+
+                let decay = (-chirp.ringdown_timer.elapsed_secs() * 10.0).exp();
+                let amplitude = decay * chirp.intensity;
+                Some((chirp.ringdown_frequency, amplitude))
+            }
+        };
+
+        if let Some((frequency, amplitude)) = sample {
+            if loudest.is_none_or(|(_, loudest_amplitude)| amplitude > loudest_amplitude) {
+                loudest = Some((frequency, amplitude));
+            }
+        }
+    }
+
+    match loudest {
+        Some((frequency, amplitude)) => {
+            oscillator.frequency = frequency;
+            oscillator.amplitude = amplitude;
+        }
+        None => oscillator.amplitude = 0.0,
+    }
+
+    oscillator.phase =
+        (oscillator.phase + oscillator.frequency * scaled_delta * std::f32::consts::TAU) % std::f32::consts::TAU;
+
+    chirp_audio_state.set(oscillator.frequency, oscillator.amplitude);
+}
+
 fn update_black_holes(
     mut black_holes: Query<(&mut Transform, &mut BlackHole)>,
     simulation_state: Res<SimulationState>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mouse_input: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
+    sim_constants: Res<SimConstants>,
 ) {
     let window = windows.single();
     if let Some(cursor_position) = window.cursor_position() {
@@ -344,7 +1262,8 @@ fn update_black_holes(
                     black_hole.mass = (black_hole.mass - 10.0).max(1.0);
                 }
 
-                black_hole.event_horizon = (black_hole.mass / 1000.0).sqrt() * 15.0;
+                black_hole.event_horizon =
+                    (black_hole.mass / 1000.0).sqrt() * sim_constants.event_horizon_coefficient;
 
                 // Update the black hole's size based on its mass
                 let size = black_hole.event_horizon * 2.0; // Diameter
@@ -354,15 +1273,22 @@ fn update_black_holes(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_input(
     mut commands: Commands,
     mut simulation_state: ResMut<SimulationState>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    black_holes: Query<Entity, With<BlackHole>>,
+    black_holes: Query<(Entity, &Transform, &BlackHole)>,
+    particles: Query<Entity, With<Particle>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     windows: Query<&Window>,
+    sim_constants: Res<SimConstants>,
 ) {
+    let black_hole_entities_with_data: Vec<(Entity, Vec3, f32)> = black_holes
+        .iter()
+        .map(|(entity, transform, black_hole)| (entity, transform.translation, black_hole.mass))
+        .collect();
     if keyboard_input.just_pressed(KeyCode::Space) {
         simulation_state.paused = !simulation_state.paused;
     }
@@ -370,42 +1296,58 @@ fn handle_input(
     if keyboard_input.just_pressed(KeyCode::KeyN) {
         let window = windows.single();
         let initial_mass = 1000.0;
-        let initial_event_horizon = ((initial_mass / 1000.0) as f32).sqrt() * 15.0;
-        let initial_size = initial_event_horizon * 2.0;
-        commands.spawn((
-            MaterialMesh2dBundle {
-                mesh: meshes.add(Circle::new(initial_size / 2.0)).into(),
-                material: materials.add(ColorMaterial::from(Color::BLACK)),
-                transform: Transform::from_xyz(
-                    rand::random::<f32>() * window.width(),
-                    window.height() - rand::random::<f32>() * window.height(), // Invert Y
-                    0.0,
-                )
-                .with_scale(Vec3::new(initial_size, initial_size, 1.0)),
-                ..default()
-            },
-            BlackHole {
-                mass: initial_mass,
-                event_horizon: initial_event_horizon,
-            },
-        ));
+        let position = Vec3::new(
+            rand::random::<f32>() * window.width(),
+            window.height() - rand::random::<f32>() * window.height(), // Invert Y
+            0.0,
+        );
+        spawn_black_hole(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            position,
+            initial_mass,
+            sim_constants.event_horizon_coefficient,
+        );
     }
 
     if keyboard_input.just_pressed(KeyCode::Tab) {
-        let black_hole_count = black_holes.iter().count();
+        let black_hole_count = black_hole_entities_with_data.len();
         if black_hole_count > 0 {
             simulation_state.selected_black_hole =
                 (simulation_state.selected_black_hole + 1) % black_hole_count;
         }
     }
 
-    if keyboard_input.just_pressed(KeyCode::Delete) {
-        let black_hole_entities: Vec<Entity> = black_holes.iter().collect();
-        if black_hole_entities.len() > 1 {
-            commands
-                .entity(black_hole_entities[simulation_state.selected_black_hole])
-                .despawn();
-            simulation_state.selected_black_hole %= black_hole_entities.len() - 1;
+    if keyboard_input.just_pressed(KeyCode::Delete) && black_hole_entities_with_data.len() > 1 {
+        commands
+            .entity(black_hole_entities_with_data[simulation_state.selected_black_hole].0)
+            .despawn();
+        simulation_state.selected_black_hole %= black_hole_entities_with_data.len() - 1;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyR) {
+        if let Some(&(_, position, mass)) =
+            black_hole_entities_with_data.get(simulation_state.selected_black_hole)
+        {
+            for entity in particles.iter() {
+                commands.entity(entity).despawn();
+            }
+
+            let mut rng = rand::thread_rng();
+            spawn_disk_particles(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &mut rng,
+                position,
+                mass,
+                simulation_state.disk_inner_radius,
+                simulation_state.disk_outer_radius,
+                sim_constants.particle_count_target,
+                sim_constants.gravitational_constant,
+            );
+            simulation_state.spawn_mode = SpawnMode::AccretionDisk;
         }
     }
 
@@ -422,67 +1364,319 @@ fn handle_input(
     if keyboard_input.pressed(KeyCode::BracketLeft) {
         simulation_state.time_scale /= 1.1;
     }
+
+    if keyboard_input.just_pressed(KeyCode::KeyG) {
+        simulation_state.mutual_gravity_enabled = !simulation_state.mutual_gravity_enabled;
+    }
+
+    if keyboard_input.just_pressed(KeyCode::KeyP) {
+        simulation_state.physics_backed = !simulation_state.physics_backed;
+    }
 }
 
-fn update_ui(
-    mut commands: Commands,
-    query: Query<Entity, With<Text>>,
+/// Dumps the current black holes and the tunable `SimulationState` fields to
+/// `scenarios/saved.json` (F5), so a particular layout can be reloaded later.
+fn save_scenario(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    black_holes: Query<(&Transform, &BlackHole)>,
     simulation_state: Res<SimulationState>,
 ) {
-    // Remove existing UI
-    for entity in query.iter() {
+    if !keyboard_input.just_pressed(KeyCode::F5) {
+        return;
+    }
+
+    let scenario = Scenario {
+        black_holes: black_holes
+            .iter()
+            .map(|(transform, black_hole)| ScenarioBlackHole {
+                mass: black_hole.mass,
+                position: transform.translation.truncate(),
+            })
+            .collect(),
+        time_scale: simulation_state.time_scale,
+        particle_size: simulation_state.particle_size,
+        spawn_mode: simulation_state.spawn_mode,
+        mutual_gravity_enabled: simulation_state.mutual_gravity_enabled,
+        theta: simulation_state.theta,
+        physics_backed: simulation_state.physics_backed,
+        disk_inner_radius: simulation_state.disk_inner_radius,
+        disk_outer_radius: simulation_state.disk_outer_radius,
+    };
+
+    match serde_json::to_string_pretty(&scenario) {
+        Ok(json) => {
+            if fs::create_dir_all(SCENARIO_DIR).is_ok() {
+                if let Err(error) = fs::write(SAVED_SCENARIO_PATH, json) {
+                    eprintln!("Failed to write scenario to {SAVED_SCENARIO_PATH}: {error}");
+                }
+            }
+        }
+        Err(error) => eprintln!("Failed to serialize scenario: {error}"),
+    }
+}
+
+/// Loads a scenario file over the keys F6 (the last saved scenario), F7
+/// (binary inspiral example) and F8 (three-body example): despawns the
+/// current black holes and particles, then rebuilds the scene from the file.
+#[allow(clippy::too_many_arguments)]
+fn load_scenario(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    black_holes: Query<Entity, With<BlackHole>>,
+    particles: Query<Entity, With<Particle>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut simulation_state: ResMut<SimulationState>,
+    sim_constants: Res<SimConstants>,
+    windows: Query<&Window>,
+) {
+    let path = if keyboard_input.just_pressed(KeyCode::F6) {
+        SAVED_SCENARIO_PATH.to_string()
+    } else if keyboard_input.just_pressed(KeyCode::F7) {
+        format!("{SCENARIO_DIR}/binary_inspiral.json")
+    } else if keyboard_input.just_pressed(KeyCode::F8) {
+        format!("{SCENARIO_DIR}/three_body.json")
+    } else {
+        return;
+    };
+
+    let json = match fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(error) => {
+            eprintln!("Failed to read scenario {path}: {error}");
+            return;
+        }
+    };
+    let scenario: Scenario = match serde_json::from_str(&json) {
+        Ok(scenario) => scenario,
+        Err(error) => {
+            eprintln!("Failed to parse scenario {path}: {error}");
+            return;
+        }
+    };
+
+    for entity in black_holes.iter().chain(particles.iter()) {
         commands.entity(entity).despawn();
     }
 
-    // Spawn new UI
-    commands.spawn(
-        TextBundle::from_sections([
-            TextSection::new(
-                "Black Hole Simulator\n",
-                TextStyle {
-                    font_size: 24.0,
-                    color: Color::WHITE,
-                    ..default()
-                },
-            ),
-            TextSection::new(
-                format!(
-                    "Keybindings:\n\
-                    Space: Pause/Resume ({})\n\
-                    Left Click: Move selected black hole\n\
-                    Up/Down Arrows: Adjust black hole mass\n\
-                    N: Add new black hole\n\
-                    Tab: Switch selected black hole\n\
-                    Delete: Remove selected black hole\n\
-                    +/-: Adjust particle size\n\
-                    \n\
-                    Black Holes: {}\n\
-                    Selected Black Hole: {}\n\
-                    Particle Size: {:.1} \n\
-                     Time Scale: {:.2}x \n\
-                    ",
-                    if simulation_state.paused {
-                        "Paused"
-                    } else {
-                        "Running"
-                    },
-                    query.iter().count(),
-                    simulation_state.selected_black_hole,
-                    simulation_state.particle_size,
-                    simulation_state.time_scale
-                ),
-                TextStyle {
-                    font_size: 16.0,
-                    color: Color::WHITE,
-                    ..default()
-                },
-            ),
-        ])
-        .with_style(Style {
-            position_type: PositionType::Absolute,
-            top: Val::Px(10.0),
-            left: Val::Px(10.0),
-            ..default()
-        }),
-    );
+    for scenario_black_hole in &scenario.black_holes {
+        spawn_black_hole(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            scenario_black_hole.position.extend(0.0),
+            scenario_black_hole.mass,
+            sim_constants.event_horizon_coefficient,
+        );
+    }
+
+    simulation_state.time_scale = scenario.time_scale;
+    simulation_state.particle_size = scenario.particle_size;
+    simulation_state.spawn_mode = scenario.spawn_mode;
+    simulation_state.mutual_gravity_enabled = scenario.mutual_gravity_enabled;
+    simulation_state.theta = scenario.theta;
+    simulation_state.physics_backed = scenario.physics_backed;
+    simulation_state.disk_inner_radius = scenario.disk_inner_radius;
+    simulation_state.disk_outer_radius = scenario.disk_outer_radius;
+    simulation_state.selected_black_hole = 0;
+
+    let window = windows.single();
+    let mut rng = rand::thread_rng();
+    match scenario.spawn_mode {
+        SpawnMode::Scatter => spawn_scatter_particles(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut rng,
+            window.width(),
+            window.height(),
+            sim_constants.particle_count_target,
+        ),
+        SpawnMode::AccretionDisk => {
+            if let Some(first_black_hole) = scenario.black_holes.first() {
+                spawn_disk_particles(
+                    &mut commands,
+                    &mut meshes,
+                    &mut materials,
+                    &mut rng,
+                    first_black_hole.position.extend(0.0),
+                    first_black_hole.mass,
+                    simulation_state.disk_inner_radius,
+                    simulation_state.disk_outer_radius.min(window.width().min(window.height()) / 2.0),
+                    sim_constants.particle_count_target,
+                    sim_constants.gravitational_constant,
+                );
+            }
+        }
+    }
+}
+
+/// Read-only HUD: keybinding help plus a per-black-hole readout highlighting
+/// the selection. `SimulationState` and `SimConstants` are edited live via
+/// the reflection-based `bevy-inspector-egui` `ResourceInspectorPlugin`
+/// windows registered in `main`, not here.
+fn draw_inspector_panel(
+    mut contexts: EguiContexts,
+    simulation_state: Res<SimulationState>,
+    black_holes: Query<(&Transform, &BlackHole)>,
+) {
+    egui::Window::new("Black Hole Simulator").show(contexts.ctx_mut(), |ui| {
+        ui.label(
+            "Space: Pause | Left Click: Move selected | Up/Down: Mass | N: New black hole\n\
+             Tab: Select next | Delete: Remove selected | R: Re-seed accretion disk\n\
+             F5/F6: Save/load scenario | F7/F8: Load example | +/-: Particle size",
+        );
+        ui.separator();
+
+        ui.label(format!("Black holes: {}", black_holes.iter().count()));
+        for (index, (transform, black_hole)) in black_holes.iter().enumerate() {
+            let label = format!(
+                "#{index}  mass {:.1}  event horizon {:.1}  @ ({:.0}, {:.0})",
+                black_hole.mass, black_hole.event_horizon, transform.translation.x, transform.translation.y
+            );
+            if index == simulation_state.selected_black_hole {
+                ui.colored_label(egui::Color32::YELLOW, format!("> {label}"));
+            } else {
+                ui.label(label);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw(index)
+    }
+
+    #[test]
+    fn force_on_self_is_zero() {
+        let body = entity(0);
+        let bodies = [Body { entity: body, position: Vec2::new(5.0, 5.0), mass: 10.0 }];
+        let tree = QuadTreeNode::build(&bodies, Vec2::ZERO, Vec2::new(10.0, 10.0));
+
+        let force = tree.force_on(body, Vec2::new(5.0, 5.0), 1.0, 0.5, 1.0);
+
+        assert_eq!(force, Vec2::ZERO);
+    }
+
+    #[test]
+    fn force_on_coincident_bodies_does_not_produce_nan() {
+        let puller = entity(0);
+        let query = entity(1);
+        let bodies = [
+            Body { entity: puller, position: Vec2::new(5.0, 5.0), mass: 10.0 },
+            Body { entity: query, position: Vec2::new(5.0, 5.0), mass: 1.0 },
+        ];
+        let tree = QuadTreeNode::build(&bodies, Vec2::ZERO, Vec2::new(10.0, 10.0));
+
+        let force = tree.force_on(query, Vec2::new(5.0, 5.0), 1.0, 0.5, 1.0);
+
+        assert!(force.is_finite(), "coincident bodies must not poison velocity with NaN: {force:?}");
+    }
+
+    #[test]
+    fn force_on_pulls_toward_more_massive_body() {
+        let heavy = entity(0);
+        let query = entity(1);
+        let bodies = [
+            Body { entity: heavy, position: Vec2::new(10.0, 0.0), mass: 1000.0 },
+            Body { entity: query, position: Vec2::ZERO, mass: 1.0 },
+        ];
+        let tree = QuadTreeNode::build(&bodies, Vec2::new(-5.0, -5.0), Vec2::new(15.0, 5.0));
+
+        let force = tree.force_on(query, Vec2::ZERO, 1.0, 0.5, 1.0);
+
+        assert!(force.x > 0.0);
+        assert!(force.y.abs() < 1e-4);
+    }
+
+    #[test]
+    fn build_aggregates_mass_and_center_of_mass() {
+        let a = entity(0);
+        let b = entity(1);
+        let bodies = [
+            Body { entity: a, position: Vec2::new(0.0, 0.0), mass: 1.0 },
+            Body { entity: b, position: Vec2::new(10.0, 0.0), mass: 1.0 },
+        ];
+
+        let tree = QuadTreeNode::build(&bodies, Vec2::ZERO, Vec2::new(10.0, 10.0));
+
+        assert_eq!(tree.mass, 2.0);
+        assert_eq!(tree.center_of_mass, Vec2::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn chirp_tau_grows_with_distance_and_shrinks_with_mass() {
+        let close = chirp_tau(10.0, 2000.0);
+        let far = chirp_tau(50.0, 2000.0);
+        assert!(far > close);
+
+        let light = chirp_tau(20.0, 1000.0);
+        let heavy = chirp_tau(20.0, 4000.0);
+        assert!(heavy < light);
+    }
+
+    #[test]
+    fn chirp_tau_has_a_floor() {
+        assert_eq!(chirp_tau(0.0, 1_000_000.0), 0.1);
+    }
+
+    #[test]
+    fn chirp_frequency_sweeps_up_toward_coalescence() {
+        let f0 = CHIRP_BASE_FREQUENCY;
+        let early = chirp_frequency(f0, 0.0);
+        let late = chirp_frequency(f0, 0.9);
+
+        assert_eq!(early, f0);
+        assert!(late > early);
+    }
+
+    #[test]
+    fn scenario_round_trips_through_json() {
+        let scenario = Scenario {
+            black_holes: vec![ScenarioBlackHole { mass: 1200.0, position: Vec2::new(340.0, 300.0) }],
+            time_scale: 1.5,
+            particle_size: 2.0,
+            spawn_mode: SpawnMode::AccretionDisk,
+            mutual_gravity_enabled: true,
+            theta: 0.8,
+            physics_backed: true,
+            disk_inner_radius: 50.0,
+            disk_outer_radius: 150.0,
+        };
+
+        let json = serde_json::to_string(&scenario).unwrap();
+        let restored: Scenario = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.black_holes.len(), 1);
+        assert_eq!(restored.black_holes[0].mass, 1200.0);
+        assert_eq!(restored.time_scale, 1.5);
+        assert_eq!(restored.spawn_mode, SpawnMode::AccretionDisk);
+        assert!(restored.mutual_gravity_enabled);
+        assert_eq!(restored.theta, 0.8);
+        assert!(restored.physics_backed);
+        assert_eq!(restored.disk_inner_radius, 50.0);
+        assert_eq!(restored.disk_outer_radius, 150.0);
+    }
+
+    #[test]
+    fn scenario_missing_new_fields_falls_back_to_setup_defaults() {
+        let legacy_json = r#"{
+            "black_holes": [],
+            "time_scale": 1.0,
+            "particle_size": 1.0
+        }"#;
+
+        let scenario: Scenario = serde_json::from_str(legacy_json).unwrap();
+
+        assert_eq!(scenario.spawn_mode, SpawnMode::Scatter);
+        assert!(!scenario.mutual_gravity_enabled);
+        assert_eq!(scenario.theta, BARNES_HUT_THETA);
+        assert!(!scenario.physics_backed);
+        assert_eq!(scenario.disk_inner_radius, DISK_INNER_RADIUS);
+        assert_eq!(scenario.disk_outer_radius, DISK_OUTER_RADIUS);
+    }
 }